@@ -13,11 +13,39 @@ use ffx_config::EnvironmentContext;
 use ffx_core::Injector;
 use fidl::endpoints::Proxy;
 use fidl_fuchsia_developer_ffx as ffx_fidl;
+use interprocess::local_socket::{LocalSocketListener, LocalSocketStream};
 use selectors::{self, VerboseError};
+use std::hash::{Hash, Hasher};
+use std::io::Write;
 use std::time::Duration;
 
 use crate::FhoToolMetadata;
 
+/// Generates a local socket name/path for handing the ffx<->subtool protocol off of stdio.
+///
+/// On Unix this is a short filesystem path under `/tmp`, since macOS/BSD cap the whole
+/// `sun_path` at around 100 bytes; on Windows it's a namespaced local-socket name. The name is
+/// derived from the subtool's own file name plus the current pid and a timestamp-derived hash
+/// so that repeated invocations of the same subtool don't collide.
+///
+/// Used by [`FhoSuite::try_from_args`] when it transparently re-invokes a subtool over the
+/// local-socket transport; see [`FhoHandler::LocalSocket`].
+pub fn generate_local_socket_name(subtool_name: &str) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    subtool_name.hash(&mut hasher);
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    let hash = hasher.finish();
+    let pid = std::process::id();
+    if cfg!(windows) {
+        format!("ffx.{pid}.{hash:x}")
+    } else {
+        format!("/tmp/ffx.{pid}.{hash:x}.sock")
+    }
+}
+
 #[derive(FromArgs)]
 #[argh(subcommand)]
 enum FhoHandler<M: FfxMain> {
@@ -26,6 +54,20 @@ enum FhoHandler<M: FfxMain> {
     Standalone(M::Command),
     /// Print out the subtool's metadata json
     Metadata(MetadataCmd),
+    /// Run the tool with the ffx<->subtool protocol carried over a local socket instead of
+    /// stdio, leaving the real stdio free for the subtool itself (e.g. for a TUI).
+    LocalSocket(LocalSocketCmd<M>),
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "local-socket", description = "run the tool with ffx communicating over a local socket rather than stdio")]
+struct LocalSocketCmd<M: FfxMain> {
+    /// name of the local socket (a Unix domain socket path, or a Windows named pipe name)
+    /// pre-created by ffx for this invocation to connect to
+    #[argh(option)]
+    local_socket: String,
+    #[argh(subcommand)]
+    command: M::Command,
 }
 
 #[derive(FromArgs)]
@@ -63,11 +105,218 @@ pub struct FhoEnvironment<'a> {
     pub ffx: &'a Ffx,
     pub context: &'a EnvironmentContext,
     pub injector: &'a dyn Injector,
+    /// Present when this invocation is carrying the ffx<->subtool protocol over a local socket
+    /// rather than stdio (see [`LocalSocketTransport`]).
+    pub local_socket: Option<&'a LocalSocketTransport>,
+}
+
+impl<'a> FhoEnvironment<'a> {
+    /// Moves this process into the foreground process group of the controlling terminal,
+    /// returning a guard that restores the previous foreground process group when dropped so
+    /// ffx regains the terminal once the subtool returns or unwinds from a panic. No signal
+    /// handler is installed, so a process killed by a signal (e.g. an interrupt) does not run the
+    /// guard and the previous foreground process group is not restored. A no-op on platforms
+    /// without process groups.
+    pub fn take_foreground(&self) -> Result<ForegroundGuard> {
+        #[cfg(unix)]
+        {
+            // SAFETY: reading and setting the foreground process group of stdin is a standard
+            // job-control operation; when stdin isn't our controlling terminal these calls only
+            // fail with an error code, which is checked below.
+            unsafe {
+                let previous_pgrp = libc::tcgetpgrp(libc::STDIN_FILENO);
+                if previous_pgrp < 0 {
+                    return Err(Self::no_terminal_error(std::io::Error::last_os_error()));
+                }
+                // We may be a member of a background process group here: if so, without
+                // ignoring SIGTTOU first, this tcsetpgrp call would deliver SIGTTOU to our own
+                // (background) process group and stop us before we ever get the terminal, per
+                // POSIX job control semantics.
+                ignore_sigttou();
+                if libc::tcsetpgrp(libc::STDIN_FILENO, libc::getpgrp()) < 0 {
+                    return Err(Self::no_terminal_error(std::io::Error::last_os_error()));
+                }
+                Ok(ForegroundGuard { previous_pgrp: Some(previous_pgrp) })
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            Ok(ForegroundGuard { previous_pgrp: None })
+        }
+    }
+
+    /// Turns a `tcgetpgrp`/`tcsetpgrp` failure into a clear user-facing error when it's because
+    /// stdin isn't a controlling terminal at all (`ENOTTY`, e.g. under CI or a piped invocation),
+    /// rather than surfacing the raw OS error.
+    #[cfg(unix)]
+    fn no_terminal_error(err: std::io::Error) -> anyhow::Error {
+        if err.raw_os_error() == Some(libc::ENOTTY) {
+            ffx_error!(format!(
+                "This tool needs a controlling terminal to run, but none is available."
+            ))
+            .into()
+        } else {
+            err.into()
+        }
+    }
+}
+
+/// Ignores `SIGTTOU` for the remainder of this process. Must be done before any `tcsetpgrp`
+/// call made by a process that isn't already in the terminal's foreground process group, or the
+/// kernel will deliver `SIGTTOU` (default action: stop the process) instead of letting the call
+/// through — the same idiom used by shells and pagers when they take the controlling terminal.
+#[cfg(unix)]
+unsafe fn ignore_sigttou() {
+    libc::signal(libc::SIGTTOU, libc::SIG_IGN);
+}
+
+/// RAII guard returned by [`FhoEnvironment::take_foreground`]. Restores the previous foreground
+/// process group of the controlling terminal on drop.
+pub struct ForegroundGuard {
+    #[cfg_attr(not(unix), allow(dead_code))]
+    previous_pgrp: Option<i32>,
+}
+
+impl Drop for ForegroundGuard {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(pgrp) = self.previous_pgrp {
+            // SAFETY: restoring a previously-saved foreground pgid on our own controlling
+            // terminal is always safe to attempt; errors here aren't actionable on the way out.
+            // Ignore SIGTTOU first for the same reason take_foreground does: we may no longer be
+            // in the terminal's foreground process group by the time this runs.
+            unsafe {
+                ignore_sigttou();
+                libc::tcsetpgrp(libc::STDIN_FILENO, pgrp);
+            }
+        }
+    }
+}
+
+/// The local-socket connection used to carry the subtool's structured writer output when a
+/// subtool was invoked in [`FhoHandler::LocalSocket`] mode, leaving the subtool's real stdio
+/// free for its own use. The `metadata` subcommand does not use it: metadata is still written
+/// to stdout or to the given output path.
+pub struct LocalSocketTransport {
+    stream: LocalSocketStream,
+}
+
+impl LocalSocketTransport {
+    /// Connects to a local socket previously created by ffx under `name`, giving up once
+    /// `timeout` elapses (e.g. because nothing is listening on it, or the socket is unreachable
+    /// due to permissions or platform limitations).
+    fn connect_with_timeout(name: &str, timeout: Duration) -> std::io::Result<Self> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let owned_name = name.to_owned();
+        std::thread::spawn(move || {
+            let _ = tx.send(LocalSocketStream::connect(owned_name.as_str()));
+        });
+        let stream = rx.recv_timeout(timeout).unwrap_or_else(|_| {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("timed out connecting to local socket {name}"),
+            ))
+        })?;
+        Ok(Self { stream })
+    }
+
+    /// A writer over this transport for sending the subtool's structured output back to ffx.
+    fn try_clone_writer(&self) -> std::io::Result<impl Write> {
+        self.stream.try_clone()
+    }
+}
+
+/// How long ffx is willing to wait for a subtool to connect to the local socket it was given
+/// before falling back to the stdio transport.
+const LOCAL_SOCKET_CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// A [`ToolRunner`] that has already delegated this invocation to a child process of the same
+/// subtool binary, re-invoked with `local-socket --local-socket <name> ...` so the
+/// ffx<->subtool protocol runs over a local socket and the child's real stdio is left free.
+/// Running it copies what the child sends over the socket to this process's stdout, waits on
+/// that child and relays its exit status.
+struct LocalSocketDelegate {
+    child: std::process::Child,
+    stream: LocalSocketStream,
+}
+
+impl LocalSocketDelegate {
+    /// Opens a listener, re-spawns the current executable with `args` moved behind a
+    /// `local-socket --local-socket <name>` prefix, and waits for it to connect. Returns `None`
+    /// (after killing the child, if one was spawned) on any failure -- an unreadable exe path, a
+    /// bind or spawn failure, or the child not connecting within
+    /// [`LOCAL_SOCKET_CONNECT_TIMEOUT`] -- so the caller can transparently fall back to running
+    /// standalone over stdio instead. On a timeout the listener stays open in the thread
+    /// blocked on `accept` until this process exits.
+    fn try_spawn(args: &[&str]) -> Option<Self> {
+        let exe = std::env::current_exe().ok()?;
+        let socket_name = generate_local_socket_name(&exe.file_name()?.to_string_lossy());
+        let listener = LocalSocketListener::bind(socket_name.as_str()).ok()?;
+
+        let spawned = std::process::Command::new(&exe)
+            .arg("local-socket")
+            .arg("--local-socket")
+            .arg(&socket_name)
+            .args(args.iter().copied())
+            .spawn();
+        let mut child = match spawned {
+            Ok(child) => child,
+            Err(_) => {
+                #[cfg(unix)]
+                let _ = std::fs::remove_file(&socket_name);
+                return None;
+            }
+        };
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(listener.accept());
+        });
+        let result = rx.recv_timeout(LOCAL_SOCKET_CONNECT_TIMEOUT);
+        // Unix local sockets are backed by a file under `/tmp`; neither `LocalSocketListener`
+        // nor the child connecting to it unlinks that file, so it's on us to clean it up here,
+        // whether the child connected or we're about to give up on it.
+        #[cfg(unix)]
+        let _ = std::fs::remove_file(&socket_name);
+        match result {
+            Ok(Ok(stream)) => Some(Self { child, stream }),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                None
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ToolRunner for LocalSocketDelegate {
+    fn forces_stdout_log(&self) -> bool {
+        false
+    }
+
+    fn wants_terminal(&self) -> bool {
+        // The child we delegated to owns the terminal, not us.
+        false
+    }
+
+    async fn run(self: Box<Self>) -> Result<(), anyhow::Error> {
+        let LocalSocketDelegate { mut child, mut stream } = *self;
+        let relay =
+            std::thread::spawn(move || std::io::copy(&mut stream, &mut std::io::stdout()));
+        let status = child.wait()?;
+        let _ = relay.join();
+        if !status.success() {
+            std::process::exit(status.code().unwrap_or(1));
+        }
+        Ok(())
+    }
 }
 
 impl MetadataCmd {
-    fn print(&self, info: &CommandInfo) -> Result<()> {
+    fn print(&self, info: &CommandInfo, supports_local_socket: bool) -> Result<()> {
         let meta = FhoToolMetadata::new(info.name, info.description);
+        let meta = ToolMetadataOutput { meta: &meta, supports_local_socket };
         match &self.output_path {
             Some(path) => serde_json::to_writer_pretty(&File::create(path)?, &meta)?,
             None => serde_json::to_writer_pretty(&std::io::stdout(), &meta)?,
@@ -76,41 +325,91 @@ impl MetadataCmd {
     }
 }
 
+/// The metadata json actually emitted by the `metadata` subcommand: the subtool's own
+/// [`FhoToolMetadata`] plus transport capabilities ffx needs in order to decide how to invoke
+/// the subtool, without having to change `FhoToolMetadata` itself.
+#[derive(serde::Serialize)]
+struct ToolMetadataOutput<'a> {
+    #[serde(flatten)]
+    meta: &'a FhoToolMetadata,
+    /// Whether this subtool's FHO runtime understands `FhoHandler::LocalSocket`. ffx uses this
+    /// to decide whether it's worth opening a local socket and attempting that transport before
+    /// falling back to stdio.
+    supports_local_socket: bool,
+}
+
 #[async_trait(?Send)]
 impl<M: FfxMain> ToolRunner for FhoTool<M> {
     fn forces_stdout_log(&self) -> bool {
-        M::forces_stdout_log()
+        // A tool that wants the real terminal needs its own writes to reach it; redirecting
+        // stdout to the log on top of that would defeat the entire point of taking the
+        // foreground, so wanting the terminal always wins over forcing the log.
+        M::forces_stdout_log() && !M::wants_terminal()
+    }
+
+    fn wants_terminal(&self) -> bool {
+        M::wants_terminal()
     }
 
     async fn run(self: Box<Self>) -> Result<(), anyhow::Error> {
         match self.command.subcommand {
-            FhoHandler::Metadata(metadata) => metadata.print(M::Command::COMMAND),
-            FhoHandler::Standalone(tool) => {
-                let cache_path = self.suite.context.get_cache_path()?;
-                std::fs::create_dir_all(&cache_path)?;
-                let hoist_cache_dir = tempfile::tempdir_in(&cache_path)?;
-                let build_info = self.suite.context.build_info();
-                let injector = self
-                    .suite
-                    .ffx
-                    .initialize_overnet(
-                        hoist_cache_dir.path(),
-                        None,
-                        DaemonVersionCheck::SameVersionInfo(build_info),
-                    )
-                    .await?;
-                let env = FhoEnvironment {
-                    ffx: &self.suite.ffx,
-                    context: &self.suite.context,
-                    injector: &injector,
-                };
-                let main = M::from_env(env, tool).await?;
-                main.main().await
+            FhoHandler::Metadata(metadata) => {
+                metadata.print(M::Command::COMMAND, M::supports_local_socket())
+            }
+            FhoHandler::Standalone(tool) => self.run_tool(tool, None).await,
+            FhoHandler::LocalSocket(LocalSocketCmd { local_socket, command: tool }) => {
+                match LocalSocketTransport::connect_with_timeout(
+                    &local_socket,
+                    LOCAL_SOCKET_CONNECT_TIMEOUT,
+                ) {
+                    Ok(transport) => self.run_tool(tool, Some(&transport)).await,
+                    Err(e) => {
+                        tracing::warn!(
+                            "falling back to the stdio transport: {e} (local socket {local_socket})"
+                        );
+                        self.run_tool(tool, None).await
+                    }
+                }
             }
         }
     }
 }
 
+impl<M: FfxMain> FhoTool<M> {
+    /// Sets up the injector and environment for this invocation and runs the tool, optionally
+    /// over the given local-socket transport.
+    async fn run_tool(
+        &self,
+        tool: M::Command,
+        local_socket: Option<&LocalSocketTransport>,
+    ) -> Result<()> {
+        let cache_path = self.suite.context.get_cache_path()?;
+        std::fs::create_dir_all(&cache_path)?;
+        let hoist_cache_dir = tempfile::tempdir_in(&cache_path)?;
+        let build_info = self.suite.context.build_info();
+        let injector = self
+            .suite
+            .ffx
+            .initialize_overnet(
+                hoist_cache_dir.path(),
+                None,
+                DaemonVersionCheck::SameVersionInfo(build_info),
+            )
+            .await?;
+        let env = FhoEnvironment {
+            ffx: &self.suite.ffx,
+            context: &self.suite.context,
+            injector: &injector,
+            local_socket,
+        };
+        let foreground = M::wants_terminal().then(|| env.take_foreground()).transpose()?;
+        let main = M::from_env(env, tool).await?;
+        let result = main.main().await;
+        drop(foreground);
+        result
+    }
+}
+
 impl<M: FfxMain> ToolSuite for FhoSuite<M> {
     fn from_env(ffx: &Ffx, context: &EnvironmentContext) -> Result<Self, anyhow::Error> {
         let ffx = ffx.clone();
@@ -127,11 +426,21 @@ impl<M: FfxMain> ToolSuite for FhoSuite<M> {
         cmd: &FfxCommandLine,
         args: &[&str],
     ) -> Result<Option<Box<dyn ToolRunner>>, argh::EarlyExit> {
-        let found = FhoTool {
-            suite: self.clone(),
-            command: ToolCommand::<M>::from_args(&Vec::from_iter(cmd.cmd_iter()), args)?,
-        };
-        Ok(Some(Box::new(found)))
+        let command = ToolCommand::<M>::from_args(&Vec::from_iter(cmd.cmd_iter()), args)?;
+
+        // No user-visible flag for this: if the tool wants the real terminal and knows how to
+        // speak the local-socket transport, transparently try to re-invoke it that way before
+        // falling back to running it standalone, over stdio, in this same process.
+        if matches!(command.subcommand, FhoHandler::Standalone(_))
+            && M::wants_terminal()
+            && M::supports_local_socket()
+        {
+            if let Some(delegate) = LocalSocketDelegate::try_spawn(args) {
+                return Ok(Some(Box::new(delegate)));
+            }
+        }
+
+        Ok(Some(Box::new(FhoTool { suite: self.clone(), command })))
     }
 
     fn redact_arg_values(
@@ -149,6 +458,23 @@ pub trait FfxTool: Sized + 'static {
     type Command: FromArgs + SubCommand + 'static;
 
     fn forces_stdout_log() -> bool;
+
+    /// Whether this tool wants exclusive control of the controlling terminal, e.g. to draw a
+    /// full-screen TUI. When true, FHO skips redirecting stdout to the log and moves the
+    /// process into the terminal's foreground process group for the duration of `from_env()`
+    /// and `main()` (see
+    /// [`FhoEnvironment::take_foreground`]).
+    fn wants_terminal() -> bool {
+        false
+    }
+
+    /// Whether this tool's FHO runtime supports the local-socket transport
+    /// (`FhoHandler::LocalSocket`). Advertised in the tool's metadata so ffx can decide whether
+    /// it's worth attempting that transport before falling back to stdio.
+    fn supports_local_socket() -> bool {
+        true
+    }
+
     async fn from_env(env: FhoEnvironment<'_>, cmd: Self::Command) -> Result<Self>;
 }
 
@@ -420,7 +746,13 @@ impl TryFromEnv for ffx_fidl::FastbootProxy {
 #[async_trait(?Send)]
 impl TryFromEnv for ffx_writer::Writer {
     async fn try_from_env(env: &FhoEnvironment<'_>) -> Result<Self> {
-        env.injector.writer().await
+        match env.local_socket {
+            Some(transport) => {
+                let socket_writer = transport.try_clone_writer()?;
+                Ok(ffx_writer::Writer::new_buffered(Box::new(socket_writer)))
+            }
+            None => env.injector.writer().await,
+        }
     }
 }
 
@@ -491,6 +823,70 @@ mod tests {
         }
     }
 
+    /// A tool that wants the terminal and would otherwise force stdout to the log -- used to
+    /// verify that wanting the terminal wins.
+    struct TerminalTool {
+        _fake_command: FakeCommand,
+    }
+
+    #[async_trait(?Send)]
+    impl FfxTool for TerminalTool {
+        type Command = FakeCommand;
+
+        fn forces_stdout_log() -> bool {
+            true
+        }
+
+        fn wants_terminal() -> bool {
+            true
+        }
+
+        async fn from_env(_env: FhoEnvironment<'_>, cmd: Self::Command) -> Result<Self> {
+            Ok(Self { _fake_command: cmd })
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl FfxMain for TerminalTool {
+        async fn main(self) -> Result<()> {
+            panic!("This should never get called")
+        }
+    }
+
+    /// A tool that wants the terminal but doesn't support the local-socket transport -- used to
+    /// verify `try_from_args` doesn't attempt to delegate to it anyway.
+    struct NoSocketTerminalTool {
+        _fake_command: FakeCommand,
+    }
+
+    #[async_trait(?Send)]
+    impl FfxTool for NoSocketTerminalTool {
+        type Command = FakeCommand;
+
+        fn forces_stdout_log() -> bool {
+            false
+        }
+
+        fn wants_terminal() -> bool {
+            true
+        }
+
+        fn supports_local_socket() -> bool {
+            false
+        }
+
+        async fn from_env(_env: FhoEnvironment<'_>, cmd: Self::Command) -> Result<Self> {
+            Ok(Self { _fake_command: cmd })
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl FfxMain for NoSocketTerminalTool {
+        async fn main(self) -> Result<()> {
+            panic!("This should never get called")
+        }
+    }
+
     fn setup_fho_items<T: FfxMain>() -> (Ffx, EnvironmentContext, FakeInjector, ToolCommand<T>) {
         let context = ffx_config::EnvironmentContext::default();
         let injector = testing::FakeInjectorBuilder::new()
@@ -516,7 +912,8 @@ mod tests {
     #[fuchsia_async::run_singlethreaded(test)]
     async fn test_run_fake_tool() {
         let (ffx, context, injector, tool_cmd) = setup_fho_items::<FakeTool>();
-        let fho_env = FhoEnvironment { ffx: &ffx, context: &context, injector: &injector };
+        let fho_env =
+            FhoEnvironment { ffx: &ffx, context: &context, injector: &injector, local_socket: None };
 
         assert_eq!(
             SIMPLE_CHECK_COUNTER.with(|counter| *counter.borrow()),
@@ -526,6 +923,7 @@ mod tests {
         let fake_tool = match tool_cmd.subcommand {
             FhoHandler::Standalone(t) => FakeTool::from_env(fho_env, t).await.unwrap(),
             FhoHandler::Metadata(_) => panic!("Not testing metadata generation"),
+            FhoHandler::LocalSocket(_) => panic!("Not testing local-socket invocation"),
         };
         assert_eq!(
             SIMPLE_CHECK_COUNTER.with(|counter| *counter.borrow()),
@@ -551,7 +949,8 @@ mod tests {
         }
 
         let (ffx, context, injector, tool_cmd) = setup_fho_items::<FakeToolWillFail>();
-        let fho_env = FhoEnvironment { ffx: &ffx, context: &context, injector: &injector };
+        let fho_env =
+            FhoEnvironment { ffx: &ffx, context: &context, injector: &injector, local_socket: None };
 
         assert_eq!(
             SIMPLE_CHECK_COUNTER.with(|counter| *counter.borrow()),
@@ -563,6 +962,7 @@ mod tests {
                 .await
                 .expect_err("Should not have been able to create tool with a negative pre-check"),
             FhoHandler::Metadata(_) => panic!("Not testing metadata generation"),
+            FhoHandler::LocalSocket(_) => panic!("Not testing local-socket invocation"),
         };
         assert_eq!(
             SIMPLE_CHECK_COUNTER.with(|counter| *counter.borrow()),
@@ -571,6 +971,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wanting_terminal_overrides_forces_stdout_log() {
+        let (ffx, context, _injector, tool_cmd) = setup_fho_items::<TerminalTool>();
+        let suite = FhoSuite::<TerminalTool> { ffx, context, _p: Default::default() };
+        let fho_tool = FhoTool { suite, command: tool_cmd };
+
+        assert!(TerminalTool::forces_stdout_log(), "test fixture should force stdout log");
+        assert!(TerminalTool::wants_terminal(), "test fixture should want the terminal");
+        assert!(
+            !fho_tool.forces_stdout_log(),
+            "a tool that wants the terminal must not also have its stdout redirected to the log"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn take_foreground_without_a_controlling_terminal_fails_clearly() {
+        let (ffx, context, injector, _tool_cmd) = setup_fho_items::<FakeTool>();
+        let env =
+            FhoEnvironment { ffx: &ffx, context: &context, injector: &injector, local_socket: None };
+
+        // `cargo test` doesn't normally run with a controlling terminal attached to stdin, so
+        // this should hit the ENOTTY path; if it doesn't (e.g. run interactively), there's
+        // nothing further to assert here.
+        if let Err(e) = env.take_foreground() {
+            assert!(
+                e.to_string().contains("controlling terminal"),
+                "expected a clear 'no controlling terminal' message, got: {e}"
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_args_never_delegates_when_tool_does_not_want_terminal() {
+        let ffx_cmd_line = ffx_command::FfxCommandLine::new(
+            None,
+            vec!["ffx".to_owned(), "fake".to_owned(), "stuff".to_owned()],
+        )
+        .unwrap();
+        let context = ffx_config::EnvironmentContext::default();
+        let ffx = ffx_cmd_line.parse::<FhoSuite<FakeTool>>();
+        let suite = FhoSuite::<FakeTool> { ffx, context, _p: Default::default() };
+
+        let runner = suite
+            .try_from_args(&ffx_cmd_line, &Vec::from_iter(ffx_cmd_line.args_iter()))
+            .expect("parsing args")
+            .expect("a runner");
+        // `LocalSocketDelegate` always reports `forces_stdout_log() == false`; `FakeTool` forces
+        // it, so seeing `true` here proves we got a plain `FhoTool` and never tried to delegate.
+        assert!(
+            runner.forces_stdout_log(),
+            "a tool that doesn't want the terminal should never be delegated to a child process"
+        );
+    }
+
+    #[test]
+    fn try_from_args_never_delegates_when_tool_does_not_support_local_socket() {
+        let ffx_cmd_line = ffx_command::FfxCommandLine::new(
+            None,
+            vec!["ffx".to_owned(), "fake".to_owned(), "stuff".to_owned()],
+        )
+        .unwrap();
+        let context = ffx_config::EnvironmentContext::default();
+        let ffx = ffx_cmd_line.parse::<FhoSuite<NoSocketTerminalTool>>();
+        let suite = FhoSuite::<NoSocketTerminalTool> { ffx, context, _p: Default::default() };
+
+        let runner = suite
+            .try_from_args(&ffx_cmd_line, &Vec::from_iter(ffx_cmd_line.args_iter()))
+            .expect("parsing args")
+            .expect("a runner");
+        // `LocalSocketDelegate` always reports `wants_terminal() == false`; this fixture wants
+        // the terminal, so seeing `true` here proves we got a plain `FhoTool` and never tried to
+        // delegate, even though it wants the terminal.
+        assert!(
+            runner.wants_terminal(),
+            "a tool that doesn't support the local-socket transport should never be delegated \
+             to a child process, even if it wants the terminal"
+        );
+    }
+
     #[fuchsia_async::run_singlethreaded(test)]
     async fn present_metadata() {
         let tmpdir = tempfile::tempdir().expect("tempdir");
@@ -586,9 +1066,9 @@ mod tests {
 
         tool.run().await.expect("running metadata command");
 
+        let raw = std::fs::read_to_string(&output_path).expect("reading metadata");
         let read_metadata: FhoToolMetadata =
-            serde_json::from_reader(File::open(output_path).expect("opening metadata"))
-                .expect("parsing metadata");
+            serde_json::from_str(&raw).expect("parsing metadata");
         assert_eq!(
             read_metadata,
             FhoToolMetadata {
@@ -598,5 +1078,39 @@ mod tests {
                 fho_details: FhoVersion::FhoVersion0 {},
             }
         );
+
+        let raw_value: serde_json::Value = serde_json::from_str(&raw).expect("parsing json");
+        assert_eq!(
+            raw_value.get("supports_local_socket"),
+            Some(&serde_json::Value::Bool(true)),
+            "metadata should advertise local-socket support: {raw}"
+        );
+    }
+
+    #[test]
+    fn local_socket_name_is_short_and_well_formed() {
+        let name = generate_local_socket_name("some-subtool-binary-name");
+        // macOS/BSD cap `sockaddr_un::sun_path` at roughly 100 bytes; stay comfortably under it.
+        assert!(name.len() < 100, "local socket name {name:?} is too long for sun_path");
+        if cfg!(windows) {
+            assert!(name.starts_with("ffx."), "unexpected windows socket name: {name:?}");
+        } else {
+            assert!(name.starts_with("/tmp/ffx."), "unexpected unix socket path: {name:?}");
+            assert!(name.ends_with(".sock"), "unexpected unix socket path: {name:?}");
+        }
+
+        // Repeated calls, even for the same subtool, shouldn't collide.
+        let other = generate_local_socket_name("some-subtool-binary-name");
+        assert_ne!(name, other);
+    }
+
+    #[test]
+    fn local_socket_connect_fails_when_nothing_is_listening() {
+        // Nobody created this socket, so this exercises both ways connecting to it can fail:
+        // an immediate "no such socket" error, or -- if the platform's connect call blocks
+        // instead -- our own timeout kicking in.
+        let socket_name = generate_local_socket_name("nonexistent-subtool-for-test");
+        LocalSocketTransport::connect_with_timeout(&socket_name, Duration::from_millis(50))
+            .expect_err("connecting to a socket nobody is listening on should fail");
     }
 }